@@ -0,0 +1,470 @@
+//! Android backend for `network_interfaces()`.
+//!
+//! Below API 24 the NDK's `libc.so` stub does not declare `getifaddrs`/
+//! `freeifaddrs`, so linking against them directly (as the rest of this
+//! crate does) fails to build. The symbols are still present in the
+//! platform `libc.so` on API 24+, so we `dlopen` it and resolve them at
+//! runtime instead of link time. Devices where that still comes up empty
+//! (API < 24, or a vendor image that strips the symbols) fall back to
+//! enumerating interfaces straight off an `AF_NETLINK` socket.
+
+use super::{
+    Addr, Flags, IfAddrV4, IfAddrV6, InterfaceType, NetworkInterface, collect_interfaces, if_type,
+    interface_mtu, interface_statistics, mac_to_string, prefix_to_ipv4_netmask,
+    prefix_to_ipv6_netmask,
+};
+use crate::Error;
+use libc::{c_char, c_int, c_void, ifaddrs};
+use std::ffi::CString;
+use std::mem;
+use std::sync::OnceLock;
+
+type GetIfAddrsFn = unsafe extern "C" fn(*mut *mut ifaddrs) -> c_int;
+type FreeIfAddrsFn = unsafe extern "C" fn(*mut ifaddrs);
+
+/// Resolved once per process and reused for every call.
+static LIBC_IFADDRS: OnceLock<Option<(GetIfAddrsFn, FreeIfAddrsFn)>> = OnceLock::new();
+
+fn libc_ifaddrs_symbols() -> Option<(GetIfAddrsFn, FreeIfAddrsFn)> {
+    *LIBC_IFADDRS.get_or_init(|| unsafe {
+        let name = CString::new("libc.so").ok()?;
+        let handle = libc::dlopen(name.as_ptr(), libc::RTLD_NOW | libc::RTLD_LOCAL);
+        if handle.is_null() {
+            return None;
+        }
+
+        let getifaddrs = resolve::<GetIfAddrsFn>(handle, c"getifaddrs")?;
+        let freeifaddrs = resolve::<FreeIfAddrsFn>(handle, c"freeifaddrs")?;
+        Some((getifaddrs, freeifaddrs))
+    })
+}
+
+unsafe fn resolve<F: Copy>(handle: *mut c_void, symbol: &'static std::ffi::CStr) -> Option<F> {
+    let ptr = unsafe { libc::dlsym(handle, symbol.as_ptr() as *const c_char) };
+    if ptr.is_null() {
+        return None;
+    }
+    // SAFETY: `F` is one of the `extern "C" fn` aliases above and `ptr` was
+    // just resolved from the matching symbol name via `dlsym`.
+    Some(unsafe { mem::transmute_copy::<*mut c_void, F>(&ptr) })
+}
+
+pub(super) fn network_interfaces() -> Result<Vec<NetworkInterface>, Error> {
+    if let Some((getifaddrs, freeifaddrs)) = libc_ifaddrs_symbols() {
+        return via_dlopen(getifaddrs, freeifaddrs);
+    }
+    netlink::network_interfaces()
+}
+
+fn via_dlopen(
+    getifaddrs: GetIfAddrsFn,
+    freeifaddrs: FreeIfAddrsFn,
+) -> Result<Vec<NetworkInterface>, Error> {
+    let mut ifaddr_ptr: *mut ifaddrs = std::ptr::null_mut();
+
+    unsafe {
+        let res = getifaddrs(&mut ifaddr_ptr);
+        if res != 0 {
+            return Err(Error::FailedToGetResource(format!(
+                "getifaddrs (dlopen) returned {res}"
+            )));
+        }
+    }
+
+    let interfaces = collect_interfaces(ifaddr_ptr);
+
+    unsafe {
+        freeifaddrs(ifaddr_ptr);
+    }
+
+    Ok(interfaces.into_values().collect())
+}
+
+/// Pure-netlink interface enumeration, used when `libc.so` doesn't expose
+/// `getifaddrs`/`freeifaddrs` at all (API < 24 or a stripped vendor image).
+mod netlink {
+    use super::{
+        Addr, Error, Flags, IfAddrV4, IfAddrV6, InterfaceType, NetworkInterface, if_type,
+        interface_mtu, interface_statistics, prefix_to_ipv4_netmask, prefix_to_ipv6_netmask,
+    };
+    use libc::{
+        AF_INET, AF_INET6, AF_NETLINK, IFA_ADDRESS, IFA_LOCAL, IFF_BROADCAST, IFF_LOOPBACK,
+        IFF_MULTICAST, IFF_RUNNING, IFF_UP, IFLA_ADDRESS, IFLA_IFNAME, NETLINK_ROUTE, NLM_F_DUMP,
+        NLM_F_REQUEST, NLMSG_DONE, NLMSG_ERROR, RTM_GETADDR, RTM_GETLINK, RTM_NEWADDR,
+        RTM_NEWLINK, SOL_SOCKET, SO_RCVTIMEO, nlmsgerr, nlmsghdr, sockaddr_nl, timeval,
+    };
+    use std::collections::BTreeMap;
+    use std::mem::{self, size_of};
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    const RTA_ALIGNTO: usize = 4;
+
+    // `libc` only re-exports the rtnetlink uAPI structs (`ifinfomsg`,
+    // `rtattr`, `ifaddrmsg`) for `target_os = "linux"`, and doesn't define
+    // `rtgenmsg` for any target. Android gets the much smaller bionic
+    // `kernel_uapi` subset, so mirror the kernel headers
+    // (`linux/rtnetlink.h`, `linux/if_addr.h`) locally instead.
+
+    #[repr(C)]
+    struct ifinfomsg {
+        ifi_family: u8,
+        __ifi_pad: u8,
+        ifi_type: u16,
+        ifi_index: i32,
+        ifi_flags: u32,
+        ifi_change: u32,
+    }
+
+    #[repr(C)]
+    struct rtattr {
+        rta_len: u16,
+        rta_type: u16,
+    }
+
+    #[repr(C)]
+    struct ifaddrmsg {
+        ifa_family: u8,
+        ifa_prefixlen: u8,
+        ifa_flags: u8,
+        ifa_scope: u8,
+        ifa_index: u32,
+    }
+
+    #[repr(C)]
+    struct rtgenmsg {
+        rtgen_family: u8,
+    }
+
+    fn rta_align(len: usize) -> usize {
+        (len + RTA_ALIGNTO - 1) & !(RTA_ALIGNTO - 1)
+    }
+
+    struct Link {
+        name: String,
+        flags: Flags,
+        mac_addr: Option<String>,
+        if_type: InterfaceType,
+        mtu: Option<u32>,
+        stats: Option<crate::network::Statistics>,
+    }
+
+    /// Owns an `AF_NETLINK` socket fd and closes it on drop, so an early
+    /// `?` return out of `network_interfaces` (e.g. from `dump`) can't leak
+    /// it.
+    struct Socket(i32);
+
+    impl Drop for Socket {
+        fn drop(&mut self) {
+            unsafe {
+                libc::close(self.0);
+            }
+        }
+    }
+
+    pub(crate) fn network_interfaces() -> Result<Vec<NetworkInterface>, Error> {
+        let socket = open_socket()?;
+
+        let links = dump(&socket, RTM_GETLINK, AF_NETLINK as u8, parse_link)?;
+        let addrs = dump(&socket, RTM_GETADDR, AF_NETLINK as u8, parse_addr)?;
+
+        drop(socket);
+
+        let mut links_by_index: BTreeMap<u32, Link> = BTreeMap::new();
+        for (index, link) in links {
+            links_by_index.insert(index, link);
+        }
+
+        let mut addrs_by_index: BTreeMap<u32, Vec<Addr>> = BTreeMap::new();
+        for (index, addr) in addrs.into_iter().flatten() {
+            addrs_by_index.entry(index).or_default().push(addr);
+        }
+
+        let interfaces = links_by_index
+            .into_iter()
+            .map(|(index, link)| NetworkInterface {
+                index,
+                name: link.name,
+                addr: addrs_by_index.remove(&index).unwrap_or_default(),
+                mac_addr: link.mac_addr,
+                flags: link.flags,
+                if_type: link.if_type,
+                mtu: link.mtu,
+                stats: link.stats,
+            })
+            .collect();
+
+        Ok(interfaces)
+    }
+
+    fn open_socket() -> Result<Socket, Error> {
+        let socket = unsafe {
+            libc::socket(
+                libc::AF_NETLINK,
+                libc::SOCK_RAW,
+                libc::NETLINK_ROUTE as i32,
+            )
+        };
+        if socket < 0 {
+            return Err(Error::FailedToGetResource(
+                "failed to open AF_NETLINK socket".to_string(),
+            ));
+        }
+        let socket = Socket(socket);
+
+        let mut addr: sockaddr_nl = unsafe { mem::zeroed() };
+        addr.nl_family = AF_NETLINK as u16;
+
+        let res = unsafe {
+            libc::bind(
+                socket.0,
+                &addr as *const sockaddr_nl as *const libc::sockaddr,
+                size_of::<sockaddr_nl>() as u32,
+            )
+        };
+        if res != 0 {
+            return Err(Error::FailedToGetResource(
+                "failed to bind AF_NETLINK socket".to_string(),
+            ));
+        }
+
+        // A reply that errors out (e.g. `EPERM` under a restrictive
+        // sandbox) is a single `NLMSG_ERROR` with no trailing
+        // `NLMSG_DONE`, so without a timeout the next `recv` would block
+        // forever; bound it instead.
+        let timeout = timeval {
+            tv_sec: 10,
+            tv_usec: 0,
+        };
+        let res = unsafe {
+            libc::setsockopt(
+                socket.0,
+                SOL_SOCKET,
+                SO_RCVTIMEO,
+                &timeout as *const timeval as *const libc::c_void,
+                size_of::<timeval>() as u32,
+            )
+        };
+        if res != 0 {
+            return Err(Error::FailedToGetResource(
+                "failed to set AF_NETLINK receive timeout".to_string(),
+            ));
+        }
+
+        Ok(socket)
+    }
+
+    /// Sends a `RTM_GET*` dump request and parses every `RTM_NEW*` reply
+    /// with `parse_one`, stopping at `NLMSG_DONE`. An `NLMSG_ERROR` reply
+    /// (the kernel's way of rejecting the request, with no `NLMSG_DONE`
+    /// following it) is surfaced as an `Err` instead of being silently
+    /// skipped, which would otherwise leave the next `recv` waiting out the
+    /// socket's receive timeout and then failing.
+    fn dump<T>(
+        socket: &Socket,
+        msg_type: u16,
+        family: u8,
+        parse_one: impl Fn(&[u8]) -> Option<(u32, T)>,
+    ) -> Result<Vec<(u32, T)>, Error> {
+        #[repr(C)]
+        struct Request {
+            header: nlmsghdr,
+            generic: rtgenmsg,
+        }
+
+        let mut request: Request = unsafe { mem::zeroed() };
+        request.header.nlmsg_len = size_of::<Request>() as u32;
+        request.header.nlmsg_type = msg_type;
+        request.header.nlmsg_flags = (NLM_F_REQUEST | NLM_F_DUMP) as u16;
+        request.header.nlmsg_seq = 1;
+        request.generic.rtgen_family = family;
+
+        let request_bytes = unsafe {
+            std::slice::from_raw_parts(
+                &request as *const Request as *const u8,
+                size_of::<Request>(),
+            )
+        };
+
+        let sent = unsafe {
+            libc::send(
+                socket.0,
+                request_bytes.as_ptr() as *const libc::c_void,
+                request_bytes.len(),
+                0,
+            )
+        };
+        if sent < 0 {
+            return Err(Error::FailedToGetResource(
+                "failed to send netlink dump request".to_string(),
+            ));
+        }
+
+        let mut results = Vec::new();
+        let mut buf = vec![0u8; 16 * 1024];
+
+        'recv: loop {
+            let received = unsafe {
+                libc::recv(
+                    socket.0,
+                    buf.as_mut_ptr() as *mut libc::c_void,
+                    buf.len(),
+                    0,
+                )
+            };
+            if received < 0 {
+                return Err(Error::FailedToGetResource(
+                    "failed to read netlink dump reply".to_string(),
+                ));
+            }
+
+            let mut offset = 0usize;
+            let received = received as usize;
+            while offset + size_of::<nlmsghdr>() <= received {
+                let header =
+                    unsafe { &*(buf[offset..].as_ptr() as *const nlmsghdr) };
+                let msg_len = header.nlmsg_len as usize;
+                if msg_len < size_of::<nlmsghdr>() || offset + msg_len > received {
+                    break;
+                }
+
+                if header.nlmsg_type == NLMSG_DONE as u16 {
+                    break 'recv;
+                }
+
+                if header.nlmsg_type == NLMSG_ERROR as u16 {
+                    let payload = &buf[offset + size_of::<nlmsghdr>()..offset + msg_len];
+                    let errno = if payload.len() >= size_of::<nlmsgerr>() {
+                        unsafe { (*(payload.as_ptr() as *const nlmsgerr)).error }
+                    } else {
+                        0
+                    };
+                    return Err(Error::FailedToGetResource(format!(
+                        "netlink dump request failed: errno {errno}"
+                    )));
+                }
+
+                if header.nlmsg_type == RTM_NEWLINK as u16
+                    || header.nlmsg_type == RTM_NEWADDR as u16
+                {
+                    let payload = &buf[offset + size_of::<nlmsghdr>()..offset + msg_len];
+                    if let Some(parsed) = parse_one(payload) {
+                        results.push(parsed);
+                    }
+                }
+
+                offset += rta_align(msg_len);
+            }
+        }
+
+        Ok(results)
+    }
+
+    fn rtattrs(buf: &[u8]) -> impl Iterator<Item = (u16, &[u8])> {
+        let mut offset = 0usize;
+        std::iter::from_fn(move || {
+            if offset + size_of::<rtattr>() > buf.len() {
+                return None;
+            }
+            let attr = unsafe { &*(buf[offset..].as_ptr() as *const rtattr) };
+            let attr_len = attr.rta_len as usize;
+            if attr_len < size_of::<rtattr>() || offset + attr_len > buf.len() {
+                return None;
+            }
+            let payload = &buf[offset + size_of::<rtattr>()..offset + attr_len];
+            offset += rta_align(attr_len);
+            Some((attr.rta_type, payload))
+        })
+    }
+
+    fn parse_link(payload: &[u8]) -> Option<(u32, Link)> {
+        if payload.len() < size_of::<ifinfomsg>() {
+            return None;
+        }
+        let info = unsafe { &*(payload.as_ptr() as *const ifinfomsg) };
+        let attrs = &payload[size_of::<ifinfomsg>()..];
+
+        let mut name = None;
+        let mut mac_addr = None;
+        for (attr_type, value) in rtattrs(attrs) {
+            match attr_type {
+                t if t == IFLA_IFNAME => {
+                    name = std::ffi::CStr::from_bytes_until_nul(value)
+                        .ok()
+                        .map(|s| s.to_string_lossy().into_owned());
+                }
+                t if t == IFLA_ADDRESS && value.len() == 6 => {
+                    mac_addr = Some(super::mac_to_string(value));
+                }
+                _ => {}
+            }
+        }
+
+        let flags = info.ifi_flags as i32;
+        let flags = Flags {
+            up: (flags & IFF_UP) != 0,
+            loopback: (flags & IFF_LOOPBACK) != 0,
+            running: (flags & IFF_RUNNING) != 0,
+            multicast: (flags & IFF_MULTICAST) != 0,
+            broadcast: (flags & IFF_BROADCAST) != 0,
+        };
+
+        let if_type = name.as_deref().map(if_type).unwrap_or(InterfaceType::Unknown);
+        let mtu = name.as_deref().and_then(interface_mtu);
+        let stats = name.as_deref().and_then(interface_statistics);
+
+        Some((
+            info.ifi_index as u32,
+            Link {
+                name: name?,
+                flags,
+                mac_addr,
+                if_type,
+                mtu,
+                stats,
+            },
+        ))
+    }
+
+    fn parse_addr(payload: &[u8]) -> Option<(u32, Addr)> {
+        if payload.len() < size_of::<ifaddrmsg>() {
+            return None;
+        }
+        let info = unsafe { &*(payload.as_ptr() as *const ifaddrmsg) };
+        let attrs = &payload[size_of::<ifaddrmsg>()..];
+
+        let mut address = None;
+        for (attr_type, value) in rtattrs(attrs) {
+            if attr_type == IFA_ADDRESS || attr_type == IFA_LOCAL {
+                address = Some(value.to_vec());
+                if attr_type == IFA_LOCAL {
+                    // Prefer the local address over the peer address of a
+                    // point-to-point link; it's what the libc path reports.
+                    break;
+                }
+            }
+        }
+        let address = address?;
+
+        let addr = match info.ifa_family as i32 {
+            AF_INET if address.len() == 4 => {
+                let ip = Ipv4Addr::new(address[0], address[1], address[2], address[3]);
+                Addr::IPv4(IfAddrV4 {
+                    ip,
+                    netmask: Some(prefix_to_ipv4_netmask(info.ifa_prefixlen)),
+                    broadcast: None,
+                })
+            }
+            AF_INET6 if address.len() == 16 => {
+                let mut octets = [0u8; 16];
+                octets.copy_from_slice(&address);
+                Addr::IPv6(IfAddrV6 {
+                    ip: Ipv6Addr::from(octets),
+                    netmask: Some(prefix_to_ipv6_netmask(info.ifa_prefixlen)),
+                })
+            }
+            _ => return None,
+        };
+
+        Some((info.ifa_index as u32, addr))
+    }
+}
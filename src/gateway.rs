@@ -0,0 +1,95 @@
+//! Default route / gateway discovery.
+//!
+//! Complements [`crate::network_interfaces`] by answering the question
+//! consumers actually have: which interface reaches the internet, and
+//! through which gateway.
+
+use crate::Error;
+use crate::network::NetworkInterface;
+use std::net::IpAddr;
+
+#[cfg(target_os = "linux")]
+mod linux;
+
+#[cfg(any(target_os = "macos", target_os = "ios", target_os = "freebsd"))]
+mod bsd;
+
+/// The system's default gateway.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Gateway {
+    /// The gateway's IP address.
+    pub ip: IpAddr,
+    /// The index of the interface the default route goes out on.
+    pub interface_index: u32,
+    /// The MAC address of the next hop, when it can be resolved from the
+    /// local ARP/neighbor table. Only Linux does this lookup; on macOS/BSD
+    /// this is always `None`.
+    pub mac_addr: Option<String>,
+}
+
+/// Get the default gateway, i.e. the route with destination `0.0.0.0`. On
+/// Linux the one with the lowest metric is picked; on macOS/BSD, the first
+/// one in the routing table dump.
+///
+/// IPv6 (`::/0`) default routes are not considered: a host whose only
+/// default route is IPv6-only will get an error here even though a gateway
+/// exists.
+pub fn default_gateway() -> Result<Gateway, Error> {
+    #[cfg(target_os = "linux")]
+    {
+        linux::default_gateway()
+    }
+
+    #[cfg(any(target_os = "macos", target_os = "ios", target_os = "freebsd"))]
+    {
+        bsd::default_gateway()
+    }
+
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "freebsd"
+    )))]
+    {
+        Err(Error::FailedToGetResource(
+            "default gateway discovery is not supported on this platform".to_string(),
+        ))
+    }
+}
+
+/// Get the network interface that the default route goes out on, i.e. the
+/// interface that actually reaches the internet rather than the first one
+/// in [`crate::network_interfaces`].
+pub fn default_interface() -> Result<NetworkInterface, Error> {
+    let gateway = default_gateway()?;
+
+    crate::network::network_interfaces()?
+        .into_iter()
+        .find(|interface| interface.index == gateway.interface_index)
+        .ok_or_else(|| {
+            Error::FailedToGetResource(format!(
+                "no interface with index {} for the default route",
+                gateway.interface_index
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_gateway() {
+        let gateway = default_gateway().expect("Failed to get default gateway");
+        println!("Gateway: {gateway:#?}");
+        assert!(gateway.interface_index > 0);
+    }
+
+    #[test]
+    fn test_default_interface() {
+        let interface = default_interface().expect("Failed to get default interface");
+        println!("Default interface: {interface:#?}");
+        assert!(!interface.addr.is_empty());
+    }
+}
@@ -0,0 +1,164 @@
+use super::Gateway;
+use crate::Error;
+use libc::{
+    AF_INET, AF_LINK, CTL_NET, NET_RT_DUMP, PF_ROUTE, RTA_DST, RTA_GATEWAY, RTF_GATEWAY, c_int,
+    rt_msghdr, sockaddr, sockaddr_dl, sockaddr_in,
+};
+use std::mem::size_of;
+use std::net::{IpAddr, Ipv4Addr};
+
+/// IPv4 only — see the doc comment on [`super::default_gateway`].
+pub(super) fn default_gateway() -> Result<Gateway, Error> {
+    let buffer = route_dump()?;
+    let (gateway_ip, interface_index, mac_addr) = parse_default_route(&buffer).ok_or_else(|| {
+        Error::FailedToGetResource("no default route in PF_ROUTE dump".to_string())
+    })?;
+
+    if interface_index == 0 {
+        return Err(Error::FailedToGetResource(
+            "default route interface no longer exists".to_string(),
+        ));
+    }
+
+    Ok(Gateway {
+        ip: IpAddr::V4(gateway_ip),
+        interface_index,
+        mac_addr,
+    })
+}
+
+/// Fetches the full `net.route` sysctl dump (`CTL_NET, PF_ROUTE, 0, AF_INET,
+/// NET_RT_DUMP, 0`), sized via the usual two-call sysctl idiom.
+fn route_dump() -> Result<Vec<u8>, Error> {
+    let mib: [c_int; 6] = [CTL_NET, PF_ROUTE, 0, AF_INET, NET_RT_DUMP, 0];
+    let mut len: usize = 0;
+
+    unsafe {
+        let res = libc::sysctl(
+            mib.as_ptr() as *mut c_int,
+            mib.len() as u32,
+            std::ptr::null_mut(),
+            &mut len,
+            std::ptr::null_mut(),
+            0,
+        );
+        if res != 0 {
+            return Err(Error::FailedToGetResource(
+                "sysctl(net.route) size query failed".to_string(),
+            ));
+        }
+    }
+
+    let mut buffer = vec![0u8; len];
+    unsafe {
+        let res = libc::sysctl(
+            mib.as_ptr() as *mut c_int,
+            mib.len() as u32,
+            buffer.as_mut_ptr() as *mut libc::c_void,
+            &mut len,
+            std::ptr::null_mut(),
+            0,
+        );
+        if res != 0 {
+            return Err(Error::FailedToGetResource(
+                "sysctl(net.route) dump failed".to_string(),
+            ));
+        }
+    }
+    buffer.truncate(len);
+
+    Ok(buffer)
+}
+
+/// Walks the `rt_msghdr` records in a `net.route` dump and returns the
+/// gateway address and outgoing interface index for the `0.0.0.0/0`
+/// route. Both come from the same record, so a host with more than one
+/// `RTF_GATEWAY` route (a static subnet route, a VPN, ...) can't end up
+/// with a gateway IP and interface pulled from different routes. The third
+/// value is the MAC of an `AF_LINK` gateway sockaddr; a record whose gateway
+/// is `AF_LINK` has no gateway IP and is skipped, so it is always `None`.
+fn parse_default_route(buffer: &[u8]) -> Option<(Ipv4Addr, u32, Option<String>)> {
+    let mut offset = 0;
+    while offset + size_of::<rt_msghdr>() <= buffer.len() {
+        let header = unsafe { &*(buffer[offset..].as_ptr() as *const rt_msghdr) };
+        let msg_len = header.rtm_msglen as usize;
+        if msg_len < size_of::<rt_msghdr>() || offset + msg_len > buffer.len() {
+            break;
+        }
+
+        if header.rtm_flags & RTF_GATEWAY != 0 {
+            let sockaddrs = &buffer[offset + size_of::<rt_msghdr>()..offset + msg_len];
+            let mut destination = None;
+            let mut gateway_ip = None;
+            let mut gateway_mac = None;
+
+            for (index, sa) in iter_sockaddrs(sockaddrs) {
+                if header.rtm_addrs & (1 << index) == 0 {
+                    continue;
+                }
+                match index {
+                    i if i == RTA_DST as usize => destination = sockaddr_ipv4(sa),
+                    i if i == RTA_GATEWAY as usize => {
+                        if let Some(ip) = sockaddr_ipv4(sa) {
+                            gateway_ip = Some(ip);
+                        } else {
+                            gateway_mac = sockaddr_mac(sa);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            if destination == Some(Ipv4Addr::UNSPECIFIED) {
+                if let Some(ip) = gateway_ip {
+                    return Some((ip, header.rtm_index as u32, gateway_mac));
+                }
+            }
+        }
+
+        offset += msg_len;
+    }
+
+    None
+}
+
+fn iter_sockaddrs(buf: &[u8]) -> impl Iterator<Item = (usize, &sockaddr)> {
+    let mut offset = 0usize;
+    let mut index = 0usize;
+    std::iter::from_fn(move || {
+        if offset + size_of::<sockaddr>() > buf.len() {
+            return None;
+        }
+        let sa = unsafe { &*(buf[offset..].as_ptr() as *const sockaddr) };
+        let len = (sa.sa_len as usize).max(size_of::<sockaddr>());
+        if offset + len > buf.len() {
+            return None;
+        }
+        let current = (index, sa);
+        offset += len;
+        index += 1;
+        Some(current)
+    })
+}
+
+fn sockaddr_ipv4(sa: &sockaddr) -> Option<Ipv4Addr> {
+    if sa.sa_family as i32 != AF_INET {
+        return None;
+    }
+    let sin = unsafe { &*(sa as *const sockaddr as *const sockaddr_in) };
+    Some(Ipv4Addr::from(u32::from_be(sin.sin_addr.s_addr)))
+}
+
+fn sockaddr_mac(sa: &sockaddr) -> Option<String> {
+    if sa.sa_family as i32 != AF_LINK {
+        return None;
+    }
+    let sdl = unsafe { &*(sa as *const sockaddr as *const sockaddr_dl) };
+    if sdl.sdl_alen != 6 {
+        return None;
+    }
+    let mac_ptr =
+        unsafe { (sdl as *const sockaddr_dl as *const u8).add(8 + sdl.sdl_nlen as usize) };
+    let mac = unsafe { std::slice::from_raw_parts(mac_ptr, 6) };
+    Some(crate::network::mac_to_string(mac))
+}
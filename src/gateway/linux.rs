@@ -0,0 +1,106 @@
+use super::Gateway;
+use crate::Error;
+use std::ffi::CString;
+use std::fs;
+use std::net::{IpAddr, Ipv4Addr};
+
+struct RouteLine {
+    iface: String,
+    destination: Ipv4Addr,
+    gateway: Ipv4Addr,
+    metric: u32,
+    mask: Ipv4Addr,
+}
+
+/// IPv4 only — see the doc comment on [`super::default_gateway`].
+pub(super) fn default_gateway() -> Result<Gateway, Error> {
+    let route = default_ipv4_route()?;
+    let interface_index = interface_index(&route.iface)?;
+    let mac_addr = arp_lookup(route.gateway);
+
+    Ok(Gateway {
+        ip: IpAddr::V4(route.gateway),
+        interface_index,
+        mac_addr,
+    })
+}
+
+/// Reads `/proc/net/route` and returns the route to `0.0.0.0/0` with the
+/// lowest metric.
+fn default_ipv4_route() -> Result<RouteLine, Error> {
+    let contents = fs::read_to_string("/proc/net/route")
+        .map_err(|e| Error::FailedToGetResource(format!("/proc/net/route: {e}")))?;
+
+    contents
+        .lines()
+        .skip(1) // header
+        .filter_map(parse_route_line)
+        .filter(|route| route.destination.is_unspecified() && route.mask.is_unspecified())
+        .min_by_key(|route| route.metric)
+        .ok_or_else(|| {
+            Error::FailedToGetResource("no default route in /proc/net/route".to_string())
+        })
+}
+
+fn parse_route_line(line: &str) -> Option<RouteLine> {
+    let mut fields = line.split_whitespace();
+    let iface = fields.next()?.to_string();
+    let destination = hex_to_ipv4(fields.next()?)?;
+    let gateway = hex_to_ipv4(fields.next()?)?;
+    let _flags = fields.next()?;
+    let _refcnt = fields.next()?;
+    let _use = fields.next()?;
+    let metric: u32 = fields.next()?.parse().ok()?;
+    let mask = hex_to_ipv4(fields.next()?)?;
+
+    Some(RouteLine {
+        iface,
+        destination,
+        gateway,
+        metric,
+        mask,
+    })
+}
+
+/// `/proc/net/route` prints each address as the raw 32-bit `in_addr` word
+/// (already in network byte order) reinterpreted as a little-endian host
+/// integer, so recovering the octets means parsing the hex as a plain
+/// number and reading it back out little-endian.
+fn hex_to_ipv4(hex: &str) -> Option<Ipv4Addr> {
+    let word = u32::from_str_radix(hex, 16).ok()?;
+    Some(Ipv4Addr::from(word.to_le_bytes()))
+}
+
+fn interface_index(name: &str) -> Result<u32, Error> {
+    let name = CString::new(name)
+        .map_err(|e| Error::FailedToGetResource(format!("invalid interface name: {e}")))?;
+    let index = unsafe { libc::if_nametoindex(name.as_ptr()) };
+    if index == 0 {
+        return Err(Error::FailedToGetResource(format!(
+            "interface {:?} no longer exists",
+            name
+        )));
+    }
+    Ok(index)
+}
+
+/// Looks up the MAC address for `ip` in the kernel's ARP/neighbor table.
+fn arp_lookup(ip: Ipv4Addr) -> Option<String> {
+    let contents = fs::read_to_string("/proc/net/arp").ok()?;
+
+    contents.lines().skip(1).find_map(|line| {
+        let mut fields = line.split_whitespace();
+        let addr: Ipv4Addr = fields.next()?.parse().ok()?;
+        if addr != ip {
+            return None;
+        }
+        let _hw_type = fields.next()?;
+        let _flags = fields.next()?;
+        let mac = fields.next()?;
+        if mac == "00:00:00:00:00:00" {
+            None
+        } else {
+            Some(mac.to_string())
+        }
+    })
+}
@@ -0,0 +1,7 @@
+mod error;
+mod gateway;
+mod network;
+
+pub use error::Error;
+pub use gateway::{Gateway, default_gateway, default_interface};
+pub use network::*;
@@ -1,15 +1,28 @@
 use crate::Error;
+#[cfg(unix)]
 use libc::size_t;
+#[cfg(unix)]
 use libc::{
     AF_INET, AF_INET6, IFF_BROADCAST, IFF_LOOPBACK, IFF_MULTICAST, IFF_RUNNING, IFF_UP,
     freeifaddrs, getifaddrs, if_nametoindex, ifaddrs, sockaddr_in, sockaddr_in6,
 };
+#[cfg(unix)]
 use std::collections::BTreeMap;
-use std::ffi::{CStr, OsString};
-use std::net::{Ipv4Addr, Ipv6Addr};
+#[cfg(unix)]
+use std::ffi::CStr;
+use std::ffi::OsString;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+#[cfg(unix)]
 use std::os::unix::ffi::OsStringExt;
+#[cfg(unix)]
 use std::ptr;
 
+#[cfg(target_os = "android")]
+mod android;
+
+#[cfg(windows)]
+mod windows;
+
 /// System network interface
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct NetworkInterface {
@@ -23,6 +36,48 @@ pub struct NetworkInterface {
     pub mac_addr: Option<String>,
     /// Interface flags
     pub flags: Flags,
+    /// Hardware type, e.g. Ethernet, loopback, or wireless
+    pub if_type: InterfaceType,
+    /// Maximum transmission unit, in bytes
+    pub mtu: Option<u32>,
+    /// Traffic counters, when the platform exposes them
+    pub stats: Option<Statistics>,
+}
+
+/// Per-interface traffic counters.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Statistics {
+    /// Bytes received
+    pub rx_bytes: u64,
+    /// Bytes transmitted
+    pub tx_bytes: u64,
+    /// Packets received
+    pub rx_packets: u64,
+    /// Packets transmitted
+    pub tx_packets: u64,
+    /// Receive errors
+    pub rx_errors: u64,
+    /// Transmit errors
+    pub tx_errors: u64,
+}
+
+/// Network interface hardware type.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum InterfaceType {
+    /// Ethernet (including most USB and virtual Ethernet-like adapters)
+    Ethernet,
+    /// Software loopback device
+    Loopback,
+    /// IEEE 802.11 wireless
+    Wireless,
+    /// Tunnel device (e.g. `tun`, `gif`, `stf`)
+    Tunnel,
+    /// Generic point-to-point link
+    PointToPoint,
+    /// PPP link
+    Ppp,
+    /// Hardware type could not be determined
+    Unknown,
 }
 
 /// Interface flags
@@ -69,6 +124,7 @@ pub struct IfAddrV6 {
     pub netmask: Option<Ipv6Addr>,
 }
 
+#[cfg(unix)]
 fn if_addr_v4(ifa: &ifaddrs, flags: &Flags) -> IfAddrV4 {
     // Get Netmask
     let mut netmask: Option<Ipv4Addr> = None;
@@ -105,6 +161,7 @@ fn if_addr_v4(ifa: &ifaddrs, flags: &Flags) -> IfAddrV4 {
     }
 }
 
+#[cfg(unix)]
 fn if_addr_v6(ifa: &ifaddrs) -> IfAddrV6 {
     let mut netmask: Option<Ipv6Addr> = None;
     if !ifa.ifa_netmask.is_null() {
@@ -119,7 +176,7 @@ fn if_addr_v6(ifa: &ifaddrs) -> IfAddrV6 {
     IfAddrV6 { ip, netmask }
 }
 
-fn mac_to_string(mac: &[u8]) -> String {
+pub(crate) fn mac_to_string(mac: &[u8]) -> String {
     let mac_addr = format!(
         "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
         mac[0], mac[1], mac[2], mac[3], mac[4], mac[5]
@@ -127,6 +184,36 @@ fn mac_to_string(mac: &[u8]) -> String {
     mac_addr
 }
 
+/// Converts a CIDR prefix length into an IPv4 netmask. Shared by the
+/// backends (Android netlink, Windows `GetAdaptersAddresses`) that only get
+/// a prefix length from their platform API instead of a netmask directly.
+#[cfg(any(target_os = "android", windows))]
+pub(crate) fn prefix_to_ipv4_netmask(prefix_len: u8) -> Ipv4Addr {
+    let bits = prefix_len.min(32);
+    let mask: u32 = if bits == 0 { 0 } else { u32::MAX << (32 - bits) };
+    Ipv4Addr::from(mask)
+}
+
+/// Converts a CIDR prefix length into an IPv6 netmask. See
+/// [`prefix_to_ipv4_netmask`].
+#[cfg(any(target_os = "android", windows))]
+pub(crate) fn prefix_to_ipv6_netmask(prefix_len: u8) -> Ipv6Addr {
+    let bits = prefix_len.min(128) as u32;
+    let mut octets = [0u8; 16];
+    for (i, octet) in octets.iter_mut().enumerate() {
+        let base = (i as u32) * 8;
+        *octet = if base + 8 <= bits {
+            0xff
+        } else if base >= bits {
+            0x00
+        } else {
+            0xffu8 << (base + 8 - bits)
+        };
+    }
+    Ipv6Addr::from(octets)
+}
+
+#[cfg(unix)]
 fn mac_addr(ifa: &ifaddrs, family: i32) -> Option<String> {
     #[cfg(any(target_os = "linux", target_os = "android"))]
     if family == libc::AF_PACKET {
@@ -157,19 +244,147 @@ fn mac_addr(ifa: &ifaddrs, family: i32) -> Option<String> {
     None
 }
 
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn if_type(name: &str) -> InterfaceType {
+    let Ok(contents) = std::fs::read_to_string(format!("/sys/class/net/{name}/type")) else {
+        return InterfaceType::Unknown;
+    };
+    let Ok(arphrd) = contents.trim().parse::<u32>() else {
+        return InterfaceType::Unknown;
+    };
+
+    // See linux/if_arp.h for the full ARPHRD_* list.
+    match arphrd {
+        1 => InterfaceType::Ethernet,
+        512 => InterfaceType::Ppp,
+        768 | 65534 => InterfaceType::Tunnel,
+        772 => InterfaceType::Loopback,
+        801 => InterfaceType::Wireless,
+        _ => InterfaceType::Unknown,
+    }
+}
+
+#[cfg(any(target_os = "macos", target_os = "ios", target_os = "freebsd"))]
+fn if_type(ifa: &ifaddrs, family: i32) -> InterfaceType {
+    if family != libc::AF_LINK {
+        return InterfaceType::Unknown;
+    }
+
+    let sdl = unsafe { *(ifa.ifa_addr as *const libc::sockaddr_dl) };
+    match sdl.sdl_type as i32 {
+        libc::IFT_ETHER => InterfaceType::Ethernet,
+        libc::IFT_LOOP => InterfaceType::Loopback,
+        libc::IFT_PPP => InterfaceType::Ppp,
+        libc::IFT_GIF | libc::IFT_STF | libc::IFT_TUNNEL => InterfaceType::Tunnel,
+        _ => InterfaceType::Unknown,
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn interface_mtu(name: &str) -> Option<u32> {
+    std::fs::read_to_string(format!("/sys/class/net/{name}/mtu"))
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+#[cfg(any(target_os = "macos", target_os = "ios", target_os = "freebsd"))]
+pub(crate) fn interface_mtu(name: &str) -> Option<u32> {
+    // `struct ifreq`'s name field is followed by a union whose first member
+    // (for `SIOCGIFMTU`) is the `ifru_mtu` int, so a plain repr(C) struct
+    // with the name bytes followed by a c_int reads it without needing the
+    // platform-specific union field name.
+    #[repr(C)]
+    struct IfreqMtu {
+        ifr_name: [libc::c_char; libc::IFNAMSIZ],
+        ifr_mtu: libc::c_int,
+    }
+
+    if name.len() >= libc::IFNAMSIZ {
+        return None;
+    }
+
+    let socket = unsafe { libc::socket(libc::AF_INET, libc::SOCK_DGRAM, 0) };
+    if socket < 0 {
+        return None;
+    }
+
+    let mut ifr: IfreqMtu = unsafe { std::mem::zeroed() };
+    for (dst, src) in ifr.ifr_name.iter_mut().zip(name.bytes()) {
+        *dst = src as libc::c_char;
+    }
+
+    let res = unsafe { libc::ioctl(socket, libc::SIOCGIFMTU, &mut ifr) };
+    unsafe {
+        libc::close(socket);
+    }
+
+    if res != 0 {
+        return None;
+    }
+    u32::try_from(ifr.ifr_mtu).ok()
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn interface_statistics(name: &str) -> Option<Statistics> {
+    let read = |counter: &str| -> Option<u64> {
+        std::fs::read_to_string(format!("/sys/class/net/{name}/statistics/{counter}"))
+            .ok()?
+            .trim()
+            .parse()
+            .ok()
+    };
+
+    Some(Statistics {
+        rx_bytes: read("rx_bytes")?,
+        tx_bytes: read("tx_bytes")?,
+        rx_packets: read("rx_packets")?,
+        tx_packets: read("tx_packets")?,
+        rx_errors: read("rx_errors")?,
+        tx_errors: read("tx_errors")?,
+    })
+}
+
+#[cfg(any(target_os = "macos", target_os = "ios", target_os = "freebsd"))]
+pub(crate) fn interface_statistics(_name: &str) -> Option<Statistics> {
+    // No sysfs-equivalent is read here; per-interface counters on BSD live
+    // behind `PF_ROUTE`/`sysctl` `RTM_IFINFO` messages, which is more than
+    // this crate currently does for a value most callers don't need.
+    None
+}
+
+/// The per-interface scalar fields that `update_interfaces`/
+/// `update_interfaces_with_mac` fill in alongside an address or MAC, bundled
+/// up so adding another one (as chunk0-4/chunk0-5 did) doesn't keep growing
+/// those functions' argument lists.
+#[cfg(unix)]
+struct InterfaceMeta {
+    if_type: InterfaceType,
+    mtu: Option<u32>,
+    stats: Option<Statistics>,
+}
+
 /// Inserts a new NetworkInterface into the BTreeMap
 /// or updates with another address in the addr list.
+#[cfg(unix)]
 fn update_interfaces(
     index: u32,
     name: String,
     addr: Addr,
     flags: Flags,
+    meta: InterfaceMeta,
     interfaces: &mut BTreeMap<u32, NetworkInterface>,
 ) {
     interfaces
         .entry(index)
         .and_modify(|i| {
             i.addr.push(addr);
+            if i.if_type == InterfaceType::Unknown {
+                i.if_type = meta.if_type;
+            }
+            i.mtu = meta.mtu;
+            i.stats = meta.stats;
         })
         .or_insert(NetworkInterface {
             index,
@@ -177,22 +392,32 @@ fn update_interfaces(
             addr: vec![addr],
             mac_addr: None,
             flags,
+            if_type: meta.if_type,
+            mtu: meta.mtu,
+            stats: meta.stats,
         });
 }
 
 /// Inserts a new NetworkInterface into the BTreeMap
 /// or updates the mac address for the given NetworkInterface.
+#[cfg(unix)]
 fn update_interfaces_with_mac(
     index: u32,
     name: String,
     mac_addr: Option<String>,
     flags: Flags,
+    meta: InterfaceMeta,
     interfaces: &mut BTreeMap<u32, NetworkInterface>,
 ) {
     interfaces
         .entry(index)
         .and_modify(|i| {
             i.mac_addr = mac_addr.clone();
+            if i.if_type == InterfaceType::Unknown {
+                i.if_type = meta.if_type;
+            }
+            i.mtu = meta.mtu;
+            i.stats = meta.stats;
         })
         .or_insert(NetworkInterface {
             index,
@@ -200,6 +425,9 @@ fn update_interfaces_with_mac(
             addr: Vec::new(),
             mac_addr,
             flags,
+            if_type: meta.if_type,
+            mtu: meta.mtu,
+            stats: meta.stats,
         });
 }
 
@@ -209,20 +437,14 @@ pub fn get_network_interfaces() -> Result<Vec<NetworkInterface>, Error> {
     network_interfaces()
 }
 
-/// Get all the network interfaces.
-pub fn network_interfaces() -> Result<Vec<NetworkInterface>, Error> {
-    let mut ifaddr_ptr: *mut ifaddrs = ptr::null_mut();
-
-    unsafe {
-        // Retrieve the linked list of interfaces
-        let res = getifaddrs(&mut ifaddr_ptr);
-        if res != 0 {
-            return Err(Error::FailedToGetResource(format!(
-                "getifaddrs returned {res}"
-            )));
-        }
-    }
-
+/// Walks the `ifaddrs` linked list returned by `getifaddrs` and builds up the
+/// `NetworkInterface` map, reading each interface's `mtu`/statistics/`if_type`
+/// as it goes. Used by the Android backend, which resolves `getifaddrs` via
+/// `dlopen` but still gets back the same `ifaddrs` shape; the other Unix
+/// targets go through [`IfAddrsIter`] instead.
+#[cfg(unix)]
+#[cfg_attr(not(target_os = "android"), allow(dead_code))]
+fn collect_interfaces(ifaddr_ptr: *mut ifaddrs) -> BTreeMap<u32, NetworkInterface> {
     let mut interfaces: BTreeMap<u32, NetworkInterface> = BTreeMap::new();
 
     let mut current_ptr = ifaddr_ptr;
@@ -254,16 +476,42 @@ pub fn network_interfaces() -> Result<Vec<NetworkInterface>, Error> {
         };
         let family = ifa_addr.sa_family as i32;
 
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        let if_type = if_type(&name);
+
+        #[cfg(any(target_os = "macos", target_os = "ios", target_os = "freebsd"))]
+        let if_type = if_type(ifa, family);
+
+        let meta = InterfaceMeta {
+            if_type,
+            mtu: interface_mtu(&name),
+            stats: interface_statistics(&name),
+        };
+
         match family {
             AF_INET => {
                 let if_addr_v4 = if_addr_v4(ifa, &flags);
                 let addr = Addr::IPv4(if_addr_v4);
-                update_interfaces(index, name.into_owned(), addr, flags, &mut interfaces);
+                update_interfaces(
+                    index,
+                    name.into_owned(),
+                    addr,
+                    flags,
+                    meta,
+                    &mut interfaces,
+                );
             }
             AF_INET6 => {
                 let if_addr_v6 = if_addr_v6(ifa);
                 let addr = Addr::IPv6(if_addr_v6);
-                update_interfaces(index, name.into_owned(), addr, flags, &mut interfaces);
+                update_interfaces(
+                    index,
+                    name.into_owned(),
+                    addr,
+                    flags,
+                    meta,
+                    &mut interfaces,
+                );
             }
             family => {
                 let mac_addr = mac_addr(ifa, family);
@@ -272,6 +520,7 @@ pub fn network_interfaces() -> Result<Vec<NetworkInterface>, Error> {
                     name.into_owned(),
                     mac_addr,
                     flags,
+                    meta,
                     &mut interfaces,
                 );
             }
@@ -280,11 +529,198 @@ pub fn network_interfaces() -> Result<Vec<NetworkInterface>, Error> {
         current_ptr = ifa.ifa_next;
     }
 
-    unsafe {
-        freeifaddrs(ifaddr_ptr);
+    interfaces
+}
+
+/// Address/flags/MAC for one interface, gathered from the raw `ifaddrs`
+/// list before the per-interface `mtu`/statistics/`if_type` syscalls run.
+/// `getifaddrs` does *not* group a given interface's entries together (on
+/// Linux, glibc emits a link dump followed by a separate address dump, so a
+/// loopback `AF_PACKET` entry and its `AF_INET`/`AF_INET6` entries can be far
+/// apart), so merging by index still requires seeing the whole list - only
+/// the comparatively expensive per-interface reads below get deferred.
+#[cfg(all(unix, not(target_os = "android")))]
+struct PendingInterface {
+    name: String,
+    addr: Vec<Addr>,
+    mac_addr: Option<String>,
+    flags: Flags,
+    #[cfg(any(target_os = "macos", target_os = "ios", target_os = "freebsd"))]
+    if_type: InterfaceType,
+}
+
+/// Walks the `ifaddrs` linked list once, merging every entry by interface
+/// index. Unlike [`collect_interfaces`] this does no `mtu`/statistics/
+/// `if_type` I/O - that's deferred to [`finish_interface`] so a caller that
+/// only consumes some of the interfaces (`network_interface_by_name`,
+/// `take(1)`) never pays for the rest.
+#[cfg(all(unix, not(target_os = "android")))]
+fn scan_pending(ifaddr_ptr: *mut ifaddrs) -> BTreeMap<u32, PendingInterface> {
+    let mut interfaces: BTreeMap<u32, PendingInterface> = BTreeMap::new();
+
+    let mut current_ptr = ifaddr_ptr;
+    while let Some(ifa) = unsafe { current_ptr.as_ref() } {
+        let name = unsafe { CStr::from_ptr(ifa.ifa_name).to_string_lossy() };
+
+        let index = unsafe { if_nametoindex(ifa.ifa_name) };
+        if index == 0 {
+            // Returns 0 on failure (e.g., interface no longer exists)
+            eprint!("Interface no longer exists: {name}");
+        }
+
+        let raw_flags = ifa.ifa_flags;
+        let flags = Flags {
+            up: (raw_flags as i32 & IFF_UP) != 0,
+            loopback: (raw_flags as i32 & IFF_LOOPBACK) != 0,
+            running: (raw_flags as i32 & IFF_RUNNING) != 0,
+            multicast: (raw_flags as i32 & IFF_MULTICAST) != 0,
+            broadcast: (raw_flags as i32 & IFF_BROADCAST) != 0,
+        };
+
+        let Some(ifa_addr) = (unsafe { ifa.ifa_addr.as_ref() }) else {
+            current_ptr = ifa.ifa_next;
+            continue;
+        };
+        let family = ifa_addr.sa_family as i32;
+
+        let entry = interfaces.entry(index).or_insert_with(|| PendingInterface {
+            name: name.into_owned(),
+            addr: Vec::new(),
+            mac_addr: None,
+            flags,
+            #[cfg(any(target_os = "macos", target_os = "ios", target_os = "freebsd"))]
+            if_type: InterfaceType::Unknown,
+        });
+
+        // On BSD the hardware type comes from this entry's `sockaddr_dl`
+        // rather than a lazily-readable syscall, so it has to be captured
+        // now while `ifa` is still available.
+        #[cfg(any(target_os = "macos", target_os = "ios", target_os = "freebsd"))]
+        if entry.if_type == InterfaceType::Unknown {
+            entry.if_type = if_type(ifa, family);
+        }
+
+        match family {
+            AF_INET => entry.addr.push(Addr::IPv4(if_addr_v4(ifa, &flags))),
+            AF_INET6 => entry.addr.push(Addr::IPv6(if_addr_v6(ifa))),
+            family => entry.mac_addr = mac_addr(ifa, family),
+        }
+
+        current_ptr = ifa.ifa_next;
+    }
+
+    interfaces
+}
+
+/// Resolves the deferred `mtu`/statistics/`if_type` fields for one
+/// interface. Only called once a [`PendingInterface`] is actually yielded.
+#[cfg(all(unix, not(target_os = "android")))]
+fn finish_interface(index: u32, pending: PendingInterface) -> NetworkInterface {
+    let name = pending.name;
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    let if_type = if_type(&name);
+
+    #[cfg(any(target_os = "macos", target_os = "ios", target_os = "freebsd"))]
+    let if_type = pending.if_type;
+
+    NetworkInterface {
+        index,
+        mtu: interface_mtu(&name),
+        stats: interface_statistics(&name),
+        name,
+        addr: pending.addr,
+        mac_addr: pending.mac_addr,
+        flags: pending.flags,
+        if_type,
+    }
+}
+
+/// Streams `NetworkInterface`s off the `getifaddrs` linked list. The address/
+/// flags/MAC merge (cheap: no syscalls beyond `if_nametoindex`) runs once,
+/// on the first call to `next`; the `mtu`/statistics/`if_type` reads for
+/// each interface (the expensive part) are deferred until that interface is
+/// actually yielded, so `network_interface_by_name`/`take(1)` only pay for
+/// the interfaces they consume. Frees the underlying list on drop, whether
+/// or not it was fully consumed.
+#[cfg(all(unix, not(target_os = "android")))]
+struct IfAddrsIter {
+    list: *mut ifaddrs,
+    pending: Option<std::collections::btree_map::IntoIter<u32, PendingInterface>>,
+}
+
+#[cfg(all(unix, not(target_os = "android")))]
+impl Drop for IfAddrsIter {
+    fn drop(&mut self) {
+        unsafe {
+            freeifaddrs(self.list);
+        }
+    }
+}
+
+#[cfg(all(unix, not(target_os = "android")))]
+impl Iterator for IfAddrsIter {
+    type Item = NetworkInterface;
+
+    fn next(&mut self) -> Option<NetworkInterface> {
+        let pending = self
+            .pending
+            .get_or_insert_with(|| scan_pending(self.list).into_iter());
+        let (index, raw) = pending.next()?;
+        Some(finish_interface(index, raw))
+    }
+}
+
+/// Get all the network interfaces, lazily. On Unix other than Android the
+/// raw interface list is merged by index into a map on the first `next`
+/// call (no `Vec` is built), and each interface's `mtu`/statistics/`if_type`
+/// reads happen only when it is yielded, so a caller that only wants the
+/// first match (e.g. `network_interface_by_name`, `take(1)`) skips them for
+/// the rest. On Windows and Android the full `Vec` is built first.
+pub fn network_interfaces_iter() -> Result<impl Iterator<Item = NetworkInterface>, Error> {
+    #[cfg(windows)]
+    {
+        return Ok(windows::network_interfaces()?.into_iter());
+    }
+
+    #[cfg(target_os = "android")]
+    {
+        // The NDK only re-exports `getifaddrs`/`freeifaddrs` for minSdk 24+,
+        // even though the platform `libc.so` itself has carried them since
+        // API 24. Resolve them at runtime and fall back to netlink when they
+        // aren't there.
+        return Ok(android::network_interfaces()?.into_iter());
+    }
+
+    #[cfg(all(unix, not(target_os = "android")))]
+    {
+        let mut ifaddr_ptr: *mut ifaddrs = ptr::null_mut();
+
+        unsafe {
+            // Retrieve the linked list of interfaces
+            let res = getifaddrs(&mut ifaddr_ptr);
+            if res != 0 {
+                return Err(Error::FailedToGetResource(format!(
+                    "getifaddrs returned {res}"
+                )));
+            }
+        }
+
+        Ok(IfAddrsIter {
+            list: ifaddr_ptr,
+            pending: None,
+        })
     }
+}
+
+/// Get all the network interfaces.
+pub fn network_interfaces() -> Result<Vec<NetworkInterface>, Error> {
+    Ok(network_interfaces_iter()?.collect())
+}
 
-    Ok(interfaces.into_values().collect())
+/// Looks up a single network interface by name.
+pub fn network_interface_by_name(name: &str) -> Result<Option<NetworkInterface>, Error> {
+    Ok(network_interfaces_iter()?.find(|ni| ni.name == name))
 }
 
 /// Gets all local IPv4 addresses that are not loopback.
@@ -321,6 +757,33 @@ pub fn local_ipv6_addresses() -> Result<Vec<Ipv6Addr>, Error> {
         .collect())
 }
 
+/// Gets every local IPv4 and IPv6 address that is not loopback. Unlike
+/// `local_ipv4_addresses`/`local_ipv6_addresses`, which only return the
+/// first matching address per interface, this returns all of them.
+pub fn local_ip_addresses() -> Result<Vec<IpAddr>, Error> {
+    Ok(network_interfaces_iter()?
+        .filter(|ni| !ni.flags.loopback)
+        .flat_map(|ni| {
+            ni.addr.into_iter().map(|addr| match addr {
+                Addr::IPv4(addr) => IpAddr::V4(addr.ip),
+                Addr::IPv6(addr) => IpAddr::V6(addr.ip),
+            })
+        })
+        .collect())
+}
+
+/// Returns `true` for an address usable for off-link communication: not
+/// loopback, not unspecified (`0.0.0.0` / `::`), and not link-local.
+pub fn is_global(ip: &IpAddr) -> bool {
+    if ip.is_loopback() || ip.is_unspecified() {
+        return false;
+    }
+    match ip {
+        IpAddr::V4(ip) => !ip.is_link_local(),
+        IpAddr::V6(ip) => !ip.is_unicast_link_local(),
+    }
+}
+
 /// This function exist for backward compatibility.
 /// Use hostname() instead.
 pub fn get_hostname() -> Result<OsString, Error> {
@@ -329,22 +792,30 @@ pub fn get_hostname() -> Result<OsString, Error> {
 
 /// Get the hostname.
 pub fn hostname() -> Result<OsString, Error> {
-    let mut buf: Vec<u8> = Vec::with_capacity(256);
-    let ptr = buf.as_mut_ptr().cast();
-    let len = buf.capacity() as size_t;
-
-    let res = unsafe { libc::gethostname(ptr, len) };
-    if res != 0 {
-        return Err(Error::FailedToGetResource(format!(
-            "gethostname returned {res}"
-        )));
+    #[cfg(windows)]
+    {
+        return windows::hostname();
     }
-    unsafe {
-        buf.as_mut_ptr().wrapping_add(len - 1).write(0);
-        let len = CStr::from_ptr(buf.as_ptr().cast()).count_bytes();
-        buf.set_len(len);
+
+    #[cfg(unix)]
+    {
+        let mut buf: Vec<u8> = Vec::with_capacity(256);
+        let ptr = buf.as_mut_ptr().cast();
+        let len = buf.capacity() as size_t;
+
+        let res = unsafe { libc::gethostname(ptr, len) };
+        if res != 0 {
+            return Err(Error::FailedToGetResource(format!(
+                "gethostname returned {res}"
+            )));
+        }
+        unsafe {
+            buf.as_mut_ptr().wrapping_add(len - 1).write(0);
+            let len = CStr::from_ptr(buf.as_ptr().cast()).count_bytes();
+            buf.set_len(len);
+        }
+        Ok(OsString::from_vec(buf))
     }
-    Ok(OsString::from_vec(buf))
 }
 
 #[cfg(test)]
@@ -378,4 +849,58 @@ mod tests {
         println!("hostname: {hostname:#?}");
         assert!(hostname.len() > 0);
     }
+
+    #[test]
+    fn test_if_type_is_populated() {
+        let interfaces = network_interfaces().expect("Failed to get network interfaces");
+        // Every interface resolves to *some* InterfaceType, falling back to
+        // `Unknown` rather than leaving the field unset.
+        for interface in &interfaces {
+            println!("{}: {:?}", interface.name, interface.if_type);
+        }
+    }
+
+    #[test]
+    fn test_mtu_and_statistics_readable() {
+        let interfaces = network_interfaces().expect("Failed to get network interfaces");
+        // mtu/statistics aren't available on every platform (e.g. containers
+        // without `/sys/class/net`), but reading them must never panic.
+        for interface in &interfaces {
+            println!(
+                "{}: mtu={:?} stats={:?}",
+                interface.name, interface.mtu, interface.stats
+            );
+        }
+    }
+
+    #[test]
+    fn test_network_interfaces_iter() {
+        let count = network_interfaces_iter()
+            .expect("Failed to iterate network interfaces")
+            .count();
+        assert!(count > 0);
+    }
+
+    #[test]
+    fn test_network_interface_by_name() {
+        let interfaces = network_interfaces().expect("Failed to get network interfaces");
+        let name = interfaces[0].name.clone();
+        let found =
+            network_interface_by_name(&name).expect("Failed to look up network interface");
+        assert_eq!(found.map(|i| i.name), Some(name));
+    }
+
+    #[test]
+    fn test_local_ip_addresses() {
+        let addresses = local_ip_addresses().expect("Failed to get local IP addresses");
+        assert!(addresses.iter().all(|ip| !ip.is_loopback()));
+    }
+
+    #[test]
+    fn test_is_global() {
+        assert!(!is_global(&"127.0.0.1".parse().unwrap()));
+        assert!(!is_global(&"0.0.0.0".parse().unwrap()));
+        assert!(!is_global(&"169.254.1.1".parse().unwrap()));
+        assert!(is_global(&"8.8.8.8".parse().unwrap()));
+    }
 }
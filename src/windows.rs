@@ -0,0 +1,169 @@
+//! Windows backend for the cross-platform network API, built on the IP
+//! Helper `GetAdaptersAddresses` call instead of the BSD `getifaddrs` used
+//! on Unix.
+
+use super::{
+    Addr, Flags, IfAddrV4, IfAddrV6, InterfaceType, NetworkInterface, mac_to_string,
+    prefix_to_ipv4_netmask, prefix_to_ipv6_netmask,
+};
+use crate::Error;
+use std::ffi::OsString;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::os::windows::ffi::OsStringExt;
+use windows::Win32::Foundation::ERROR_SUCCESS;
+use windows::Win32::NetworkManagement::IpHelper::{
+    GAA_FLAG_INCLUDE_PREFIX, GET_ADAPTERS_ADDRESSES_FLAGS, GetAdaptersAddresses,
+    IF_TYPE_ETHERNET_CSMACD, IF_TYPE_IEEE80211, IF_TYPE_PPP, IF_TYPE_SOFTWARE_LOOPBACK,
+    IF_TYPE_TUNNEL, IP_ADAPTER_ADDRESSES_LH, IP_ADAPTER_NO_MULTICAST, IfOperStatusUp,
+};
+use windows::Win32::Networking::WinSock::{AF_INET, AF_INET6, AF_UNSPEC, SOCKADDR_IN, SOCKADDR_IN6};
+use windows::Win32::System::SystemInformation::{
+    ComputerNamePhysicalDnsHostname, GetComputerNameExW,
+};
+use windows::core::PWSTR;
+
+/// Calls `GetAdaptersAddresses` with a growing buffer, as recommended by the
+/// Win32 docs (the adapter list can change size between the sizing call and
+/// the real one).
+fn adapter_addresses() -> Result<Vec<u8>, Error> {
+    let mut size: u32 = 16 * 1024;
+
+    for _ in 0..3 {
+        let mut buffer = vec![0u8; size as usize];
+        let result = unsafe {
+            GetAdaptersAddresses(
+                AF_UNSPEC.0 as u32,
+                GET_ADAPTERS_ADDRESSES_FLAGS(GAA_FLAG_INCLUDE_PREFIX.0),
+                None,
+                Some(buffer.as_mut_ptr() as *mut IP_ADAPTER_ADDRESSES_LH),
+                &mut size,
+            )
+        };
+
+        if result == ERROR_SUCCESS.0 {
+            buffer.truncate(size as usize);
+            return Ok(buffer);
+        }
+    }
+
+    Err(Error::FailedToGetResource(
+        "GetAdaptersAddresses did not succeed".to_string(),
+    ))
+}
+
+fn mac_addr(adapter: &IP_ADAPTER_ADDRESSES_LH) -> Option<String> {
+    if adapter.PhysicalAddressLength == 6 {
+        Some(mac_to_string(&adapter.PhysicalAddress[..6]))
+    } else {
+        None
+    }
+}
+
+fn if_type(adapter: &IP_ADAPTER_ADDRESSES_LH) -> InterfaceType {
+    match adapter.IfType {
+        IF_TYPE_SOFTWARE_LOOPBACK => InterfaceType::Loopback,
+        IF_TYPE_ETHERNET_CSMACD => InterfaceType::Ethernet,
+        IF_TYPE_IEEE80211 => InterfaceType::Wireless,
+        IF_TYPE_PPP => InterfaceType::Ppp,
+        IF_TYPE_TUNNEL => InterfaceType::Tunnel,
+        _ => InterfaceType::Unknown,
+    }
+}
+
+fn unicast_addresses(adapter: &IP_ADAPTER_ADDRESSES_LH) -> Vec<Addr> {
+    let mut addr = Vec::new();
+
+    let mut unicast_ptr = adapter.FirstUnicastAddress;
+    while let Some(unicast) = unsafe { unicast_ptr.as_ref() } {
+        if let Some(sockaddr) = unsafe { unicast.Address.lpSockaddr.as_ref() } {
+            match sockaddr.sa_family {
+                AF_INET => {
+                    let sin = unsafe { *(sockaddr as *const _ as *const SOCKADDR_IN) };
+                    let ip = Ipv4Addr::from(u32::from_be(unsafe { sin.sin_addr.S_un.S_addr }));
+                    addr.push(Addr::IPv4(IfAddrV4 {
+                        ip,
+                        netmask: Some(prefix_to_ipv4_netmask(unicast.OnLinkPrefixLength)),
+                        broadcast: None,
+                    }));
+                }
+                AF_INET6 => {
+                    let sin6 = unsafe { *(sockaddr as *const _ as *const SOCKADDR_IN6) };
+                    let ip = Ipv6Addr::from(unsafe { sin6.sin6_addr.u.Byte });
+                    addr.push(Addr::IPv6(IfAddrV6 {
+                        ip,
+                        netmask: Some(prefix_to_ipv6_netmask(unicast.OnLinkPrefixLength)),
+                    }));
+                }
+                _ => {}
+            }
+        }
+
+        unicast_ptr = unicast.Next;
+    }
+
+    addr
+}
+
+pub(super) fn network_interfaces() -> Result<Vec<NetworkInterface>, Error> {
+    let buffer = adapter_addresses()?;
+    let mut interfaces = Vec::new();
+
+    let mut adapter_ptr = buffer.as_ptr() as *const IP_ADAPTER_ADDRESSES_LH;
+    while let Some(adapter) = unsafe { adapter_ptr.as_ref() } {
+        let name = unsafe { adapter.FriendlyName.to_string() }.unwrap_or_default();
+        let index = if adapter.Ipv6IfIndex != 0 {
+            adapter.Ipv6IfIndex
+        } else {
+            unsafe { adapter.Anonymous1.Anonymous.IfIndex }
+        };
+
+        let up = adapter.OperStatus == IfOperStatusUp;
+        let flags = Flags {
+            up,
+            loopback: adapter.IfType == IF_TYPE_SOFTWARE_LOOPBACK,
+            running: up,
+            multicast: unsafe { adapter.Anonymous2.Flags } & IP_ADAPTER_NO_MULTICAST == 0,
+            broadcast: matches!(adapter.IfType, IF_TYPE_ETHERNET_CSMACD | IF_TYPE_IEEE80211),
+        };
+
+        interfaces.push(NetworkInterface {
+            index,
+            name,
+            addr: unicast_addresses(adapter),
+            mac_addr: mac_addr(adapter),
+            flags,
+            if_type: if_type(adapter),
+            mtu: Some(adapter.Mtu),
+            stats: None,
+        });
+
+        adapter_ptr = adapter.Next;
+    }
+
+    Ok(interfaces)
+}
+
+pub(super) fn hostname() -> Result<OsString, Error> {
+    let mut len: u32 = 0;
+    unsafe {
+        // First call with a null buffer just to learn the required size.
+        let _ = GetComputerNameExW(ComputerNamePhysicalDnsHostname, PWSTR::null(), &mut len);
+    }
+
+    let mut buf: Vec<u16> = vec![0; len as usize];
+    let ok = unsafe {
+        GetComputerNameExW(
+            ComputerNamePhysicalDnsHostname,
+            PWSTR(buf.as_mut_ptr()),
+            &mut len,
+        )
+    };
+    if !ok.as_bool() {
+        return Err(Error::FailedToGetResource(
+            "GetComputerNameExW failed".to_string(),
+        ));
+    }
+
+    buf.truncate(len as usize);
+    Ok(OsString::from_wide(&buf))
+}